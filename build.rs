@@ -0,0 +1,66 @@
+//! Scans every file under `LOCALIZATION_DIR` at build time and generates
+//! `VALID_KEYS`, the list of known translation keys the `tr!` macro in
+//! `src/lib.rs` checks literals against at compile time.
+//!
+//! The regexes below mirror `ONE_LINE_REGEX`/`MULTI_LINE_REGEX` in `src/lib.rs`,
+//! and the JSON/extension dispatch mirrors `TRANSLATIONS_HASHMAP`'s loop there;
+//! they're duplicated here because `build.rs` can't depend on the crate it builds.
+
+use std::{collections::BTreeSet, env, fs, path::Path};
+
+fn main() {
+    let localization_dir = match env::var("LOCALIZATION_DIR") {
+        Ok(dir) => dir,
+        Err(_) => return,
+    };
+
+    println!("cargo:rerun-if-env-changed=LOCALIZATION_DIR");
+    println!("cargo:rerun-if-changed={localization_dir}");
+
+    let one_line_regex = regex::Regex::new(r#""([\w\W]+?)"\s*=>\s*"([\w\W]+?)""#).unwrap();
+    let multi_line_regex = regex::Regex::new(r#"\#"([\w\W]+?)"\#\s*=>\s*\#"([\w\W]+?)"\#"#).unwrap();
+    // `plural "key" { one => "..." other => "..." }` blocks use a different syntax
+    // that `trl_plural` parses separately; strip them out before running the
+    // regexes above so their inner `category => "..."` lines can't be mistaken
+    // for translation keys.
+    let plural_block_regex = regex::Regex::new(r#"plural\s+"[\w\W]+?"\s*\{[\w\W]+?\}"#).unwrap();
+
+    let mut keys: BTreeSet<String> = BTreeSet::new();
+
+    if let Ok(entries) = fs::read_dir(&localization_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(contents) = fs::read_to_string(&path) else {
+                continue;
+            };
+
+            let is_json = path.extension().map(|ext| ext == "json").unwrap_or(false) || contents.trim_start().starts_with('{');
+
+            if is_json {
+                if let Ok(translations) = serde_json::from_str::<std::collections::HashMap<String, serde_json::Value>>(&contents) {
+                    keys.extend(translations.into_keys());
+                }
+                continue;
+            }
+
+            let contents = plural_block_regex.replace_all(&contents, "");
+
+            for cap in one_line_regex.captures_iter(&contents) {
+                keys.insert(cap[1].to_string());
+            }
+
+            for cap in multi_line_regex.captures_iter(&contents) {
+                keys.insert(cap[1].to_string());
+            }
+        }
+    }
+
+    let mut generated = String::from("#[doc(hidden)]\npub static VALID_KEYS: &[&str] = &[\n");
+    for key in &keys {
+        generated.push_str(&format!("    {key:?},\n"));
+    }
+    generated.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("valid_keys.rs"), generated).unwrap();
+}
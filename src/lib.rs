@@ -37,15 +37,35 @@
 //! 
 //! Bu yazının çevirisi bir sonraki tırnak içindeki yazıdır."#
 //! ```
+//! Example plural block, for use with `trl_plural` (any of `zero`/`one`/`two`/`few`/`many`/`other` may be given; `other` is the fallback):
+//! ```text
+//! plural "file_count" {
+//!     one => "$count file"
+//!     other => "$count files"
+//! }
+//! ```
 //! ## Usage
-//! - Use `tr("Text")` if you want to use user's computer's LANG environment variable(like: `LANG=en_US.UTF-8`) at startup to determine their system language and translate the program to that language.
-//! - Use `trl("Text", "en_US")` if you want to use your own variable to store user's language. For example you can change the program's language by changing the second parameter of `trl` without restarting the app. 
-
-use std::{collections::HashMap, env};
+//! - Use `tr("Text")` if you want to use the detected system locale (explicit `set_locale`, then `LANG`/`LC_ALL`, then platform detection) to translate the program to that language.
+//! - Use `set_locale("tr_TR")` to override the detected locale at runtime, e.g. to let the user switch language from an in-app menu on platforms without a `LANG` variable (Windows, wasm). Use `clear_locale()` to go back to environment/platform detection.
+//! - Use `tr!("Text")` instead of `tr("Text")` to catch typos in translation keys at compile time: `build.rs` scans `LOCALIZATION_DIR` (custom format and JSON files) for the set of valid keys, and the macro fails to compile if the literal you pass isn't among them, compared by value so multi-line/raw-string keys work too. `trl_plural`'s `plural { ... }` blocks aren't covered by `tr!` - check those at runtime.
+//! - Translation files may also be JSON objects of `"key": "value"` pairs (e.g. `{ "Hello": "Merhaba" }`) - files are dispatched to the JSON parser by a `.json` extension or a leading `{`, so you can reuse translation assets already maintained in JSON for other parts of your stack. The language code is the file name with any extension stripped, so `localization/en_US.json` is looked up as `"en_US"`, same as a `localization/en_US` file in the custom format.
+//! - Use `trl_plural("file_count", "en_US", count)` for count-dependent messages. Translation files can associate a key with `zero`/`one`/`two`/`few`/`many`/`other` variants in a `plural "key" { ... }` block, and the matching CLDR plural category for `count` in that language is selected automatically.
+//! - Use `trl("Text", "en_US")` if you want to use your own variable to store user's language. For example you can change the program's language by changing the second parameter of `trl` without restarting the app.
+//! - Use `tr_args("Text $name", &args)` / `trl_args("Text $name", "en_US", &args)` if your text contains `$placeholder` tokens that need to be filled in with runtime values. Translators are free to reorder the placeholders per language.
+//! - Use `set_fallback_language("en_US")` if you want `tr`/`trl` to fall back to another language instead of returning the raw text when the requested language or key is missing. A locale like `tr_TR` also falls back to its base language (`tr`) automatically before the fallback language is tried. Use `clear_fallback_language()` to remove it again.
+//! - Use `missing_translations()` to get a `lang -> keys` report of every translation that was missing during the run. Each missing `(lang, text)` pair is only warned about once, so hot paths don't flood your logs.
+
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    env,
+    sync::Mutex,
+};
 
 use include_dir::{include_dir, Dir};
 use lazy_regex::{lazy_regex, Lazy, Regex};
 use lazy_static::lazy_static;
+use regex::Captures;
 
 lazy_static! {
     static ref TRANSLATIONS_HASHMAP: HashMap<&'static str, HashMap<&'static str, &'static str>> = {
@@ -54,17 +74,201 @@ lazy_static! {
         // Read all files and add them to the hashmap:
         static LOCALIZATION_DIR__: Dir<'_> = include_dir!("$LOCALIZATION_DIR");
         for file in LOCALIZATION_DIR__.files() {
-            let translation: HashMap<&'static str, &'static str> = create_translation_hashmap(file.contents_utf8().unwrap());
+            let contents = file.contents_utf8().unwrap();
+            let is_json = file.path().extension().map(|ext| ext == "json").unwrap_or(false) || contents.trim_start().starts_with('{');
+
+            let translation: HashMap<&'static str, &'static str> = if is_json {
+                create_translation_hashmap_json(contents)
+            } else {
+                create_translation_hashmap(strip_plural_blocks(contents))
+            };
+
+            all_translations.insert(file.path().file_stem().unwrap().to_str().unwrap(), translation);
+        }
+
+        all_translations
+    };
+
+    static ref FALLBACK_LANGUAGE: Mutex<Option<String>> = Mutex::new(None);
+
+    static ref MISSING_TRANSLATIONS: Mutex<HashSet<(String, String)>> = Mutex::new(HashSet::new());
+
+    static ref LOCALE_OVERRIDE: Mutex<Option<String>> = Mutex::new(None);
+
+    static ref PLURAL_TRANSLATIONS_HASHMAP: HashMap<&'static str, HashMap<&'static str, HashMap<PluralCategory, &'static str>>> = {
+        let mut all_translations: HashMap<&'static str, HashMap<&'static str, HashMap<PluralCategory, &'static str>>> = HashMap::new();
+
+        static LOCALIZATION_DIR__: Dir<'_> = include_dir!("$LOCALIZATION_DIR");
+        for file in LOCALIZATION_DIR__.files() {
+            let Some(contents) = file.contents_utf8() else {
+                continue;
+            };
+            let translations = create_plural_hashmap(contents);
 
-            all_translations.insert(file.path().file_name().unwrap().to_str().unwrap(), translation);
+            if !translations.is_empty() {
+                all_translations.insert(file.path().file_stem().unwrap().to_str().unwrap(), translations);
+            }
         }
 
         all_translations
     };
 }
 
+/// Set a language that `tr`/`trl` will consult when the requested language file or
+/// key doesn't have a translation, before giving up and returning the text as-is.
+/// ```rust,ignore
+/// use simple_localization::set_fallback_language;
+///
+/// set_fallback_language("en_US");
+/// ```
+pub fn set_fallback_language(lang: &str) {
+    *FALLBACK_LANGUAGE.lock().unwrap() = Some(lang.to_string());
+}
+
+/// Clears the fallback language previously set via `set_fallback_language`, so
+/// `tr`/`trl` go back to returning the text as-is when the requested language or
+/// key is missing.
+/// ```rust,ignore
+/// use simple_localization::clear_fallback_language;
+///
+/// clear_fallback_language();
+/// ```
+pub fn clear_fallback_language() {
+    *FALLBACK_LANGUAGE.lock().unwrap() = None;
+}
+
+/// Returns the base language of a locale string, e.g. `"tr"` for `"tr_TR"`, or
+/// `None` if `lang` has no region/script suffix to strip.
+fn base_language(lang: &str) -> Option<&str> {
+    lang.split(['_', '-'])
+        .next()
+        .filter(|base| *base != lang)
+}
+
+fn lookup(lang: &str, text: &str) -> Option<&'static str> {
+    TRANSLATIONS_HASHMAP.get(lang)?.get(text).copied()
+}
+
+/// Looks up `text` in `lang`, degrading to `lang`'s base language (`tr_TR` -> `tr`)
+/// when there's no file for the full locale.
+fn lookup_with_base(lang: &str, text: &str) -> Option<&'static str> {
+    lookup(lang, text).or_else(|| base_language(lang).and_then(|base| lookup(base, text)))
+}
+
+/// Looks up the plural-variant map for `key` in `lang`, degrading to `lang`'s base
+/// language the same way `lookup_with_base` does for plain translations.
+fn lookup_plural(lang: &str, key: &str) -> Option<&'static HashMap<PluralCategory, &'static str>> {
+    PLURAL_TRANSLATIONS_HASHMAP
+        .get(lang)
+        .and_then(|translations| translations.get(key))
+        .or_else(|| {
+            base_language(lang)
+                .and_then(|base| PLURAL_TRANSLATIONS_HASHMAP.get(base))
+                .and_then(|translations| translations.get(key))
+        })
+}
+
+/// Resolves `key`'s variant for `category` in `lang` (degrading to the `other`
+/// variant when `category` has none of its own), trying `lang`'s base language
+/// the same way `lookup_with_base` does for plain translations.
+fn resolve_plural_variant(lang: &str, key: &str, category: PluralCategory) -> Option<&'static str> {
+    lookup_plural(lang, key)
+        .and_then(|variants| variants.get(&category).or_else(|| variants.get(&PluralCategory::Other)))
+        .copied()
+}
+
+fn language_exists(lang: &str) -> bool {
+    TRANSLATIONS_HASHMAP.contains_key(lang) || base_language(lang).is_some_and(|base| TRANSLATIONS_HASHMAP.contains_key(base))
+}
+
+/// Reports a missing `(lang, text)` translation, printing a warning only the first
+/// time this particular pair is seen so hot paths don't flood the logs.
+fn report_missing(lang: &str, text: &str) {
+    let mut seen = MISSING_TRANSLATIONS.lock().unwrap();
+    if seen.insert((lang.to_string(), text.to_string())) {
+        if !language_exists(lang) {
+            eprintln!("Translation Error: localization/{lang} doesn't exist");
+        } else {
+            eprintln!("Translation Error: No translation of '{text}' exists in '{lang}' language");
+        }
+    }
+}
+
+/// Returns every `(text, lang)` lookup that had no translation during this run, as a
+/// map from language to the list of keys that were missing in it. Useful for CI to
+/// assert on translation coverage.
+/// ```rust,ignore
+/// use simple_localization::missing_translations;
+///
+/// let report = missing_translations();
+/// assert!(report.is_empty(), "untranslated keys: {report:?}");
+/// ```
+pub fn missing_translations() -> HashMap<String, Vec<String>> {
+    let seen = MISSING_TRANSLATIONS.lock().unwrap();
+    let mut report: HashMap<String, Vec<String>> = HashMap::new();
+
+    for (lang, text) in seen.iter() {
+        report.entry(lang.clone()).or_default().push(text.clone());
+    }
+
+    report
+}
+
 static ONE_LINE_REGEX: Lazy<Regex> = lazy_regex!(r#""([\w\W]+?)"\s*=>\s*"([\w\W]+?)""#);
 static MULTI_LINE_REGEX: Lazy<Regex> = lazy_regex!(r#"\#"([\w\W]+?)"\#\s*=>\s*\#"([\w\W]+?)"\#"#);
+static PLACEHOLDER_REGEX: Lazy<Regex> = lazy_regex!(r"\$([a-zA-Z0-9_-]+)");
+static PLURAL_BLOCK_REGEX: Lazy<Regex> = lazy_regex!(r#"plural\s+"([\w\W]+?)"\s*\{([\w\W]+?)\}"#);
+static PLURAL_VARIANT_REGEX: Lazy<Regex> = lazy_regex!(r#"(zero|one|two|few|many|other)\s*=>\s*"([\w\W]+?)""#);
+
+/// A CLDR plural category. Which categories a language actually uses (and what they
+/// mean numerically) varies per language - see `plural_category`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    fn parse(category: &str) -> Option<Self> {
+        match category {
+            "zero" => Some(Self::Zero),
+            "one" => Some(Self::One),
+            "two" => Some(Self::Two),
+            "few" => Some(Self::Few),
+            "many" => Some(Self::Many),
+            "other" => Some(Self::Other),
+            _ => None,
+        }
+    }
+}
+
+/// Computes the CLDR plural category for `count` in `lang`, implementing the common
+/// cases inline: English-like languages use `one` for `n == 1` and `other` otherwise;
+/// Turkish and most Asian languages always use `other`; Arabic has a six-way split.
+fn plural_category(lang: &str, count: u64) -> PluralCategory {
+    match base_language(lang).unwrap_or(lang) {
+        "ar" => match count {
+            0 => PluralCategory::Zero,
+            1 => PluralCategory::One,
+            2 => PluralCategory::Two,
+            n if (3..=10).contains(&(n % 100)) => PluralCategory::Few,
+            n if (11..=99).contains(&(n % 100)) => PluralCategory::Many,
+            _ => PluralCategory::Other,
+        },
+        "tr" | "ja" | "ko" | "zh" | "th" | "vi" | "id" | "ms" => PluralCategory::Other,
+        _ => {
+            if count == 1 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+    }
+}
 
 fn create_translation_hashmap( localization_string: &str ) -> HashMap<&str, &str> {
     let mut translations: HashMap<&str, &str> = HashMap::new();
@@ -86,6 +290,72 @@ fn create_translation_hashmap( localization_string: &str ) -> HashMap<&str, &str
     translations
 }
 
+/// Parses a translation file whose contents are a JSON object of `"key": "value"`
+/// pairs, e.g. `{ "Hello": "Merhaba" }`. Lets teams reuse translation assets already
+/// maintained in JSON for their web/Python backends alongside this crate's own
+/// `"key" => "value"` format.
+///
+/// Deserializes into owned `String`s rather than borrowing `&str` from the input:
+/// any value containing an escape sequence (`\n`, `\"`, `\uXXXX`, ...) can't be
+/// zero-copy-borrowed by serde, which would otherwise fail the whole file's parse
+/// over a single escaped value. The owned strings are then leaked to match the
+/// `&'static str` shape `TRANSLATIONS_HASHMAP` uses for every other source.
+fn create_translation_hashmap_json(localization_string: &str) -> HashMap<&'static str, &'static str> {
+    let translations: HashMap<String, String> = match serde_json::from_str(localization_string) {
+        Ok(translations) => translations,
+        Err(err) => {
+            eprintln!("Translation Error: failed to parse JSON translation file: {err}");
+            return HashMap::new();
+        }
+    };
+
+    translations
+        .into_iter()
+        .map(|(key, value)| (leak_string(key), leak_string(value)))
+        .collect()
+}
+
+fn leak_string(value: String) -> &'static str {
+    Box::leak(value.into_boxed_str())
+}
+
+/// Strips `plural "key" { one => "..." other => "..." }` blocks out of a custom-format
+/// translation file before it's handed to `create_translation_hashmap`, the same way
+/// `build.rs` does before collecting `tr!`'s valid keys - otherwise the non-greedy
+/// `ONE_LINE_REGEX` can span from a plural block's opening quote all the way through
+/// to the next ordinary entry's closing quote, swallowing it into one bogus key.
+fn strip_plural_blocks(contents: &'static str) -> &'static str {
+    match PLURAL_BLOCK_REGEX.replace_all(contents, "") {
+        Cow::Borrowed(stripped) => stripped,
+        Cow::Owned(stripped) => leak_string(stripped),
+    }
+}
+
+/// Parses the `plural "key" { one => "..." other => "..." }` blocks out of a
+/// translation file into a key -> (category -> variant) map, for use by `trl_plural`.
+fn create_plural_hashmap(localization_string: &str) -> HashMap<&str, HashMap<PluralCategory, &str>> {
+    let mut translations: HashMap<&str, HashMap<PluralCategory, &str>> = HashMap::new();
+
+    for block in PLURAL_BLOCK_REGEX.captures_iter(localization_string) {
+        let (Some(key), Some(body)) = (block.get(1), block.get(2)) else {
+            continue;
+        };
+
+        let mut variants: HashMap<PluralCategory, &str> = HashMap::new();
+        for variant in PLURAL_VARIANT_REGEX.captures_iter(body.as_str()) {
+            if let (Some(category), Some(value)) = (variant.get(1), variant.get(2)) {
+                if let Some(category) = PluralCategory::parse(category.as_str()) {
+                    variants.insert(category, value.as_str());
+                }
+            }
+        }
+
+        translations.insert(key.as_str(), variants);
+    }
+
+    translations
+}
+
 /// Get translation of the `text` in a spesific language
 /// If translation exists returns the translation
 /// else returns the `text` back.
@@ -103,61 +373,298 @@ fn create_translation_hashmap( localization_string: &str ) -> HashMap<&str, &str
 /// ```
 /// 
 pub fn trl<'a, 'b>(text: &'a str, lang: &'b str) -> &'a str {
-    match TRANSLATIONS_HASHMAP.get(lang) {
-        Some(language_translations) => {
-            match language_translations.get(text) {
-                Some(&value) => value,
-                None => {
-                    eprintln!("Translation Error: No translation of '{text}' exists in '{lang}' language");
-                    return text;
-                }
+    if let Some(value) = lookup_with_base(lang, text) {
+        return value;
+    }
+
+    let fallback_lang = FALLBACK_LANGUAGE.lock().unwrap().clone();
+    if let Some(fallback_lang) = &fallback_lang {
+        if fallback_lang != lang {
+            if let Some(value) = lookup_with_base(fallback_lang, text) {
+                return value;
             }
         }
-        None => {
-            eprintln!("Translation Error: localization/{lang} doesn't exist");
-            return text;
+    }
+
+    report_missing(lang, text);
+    text
+}
+
+/// Overrides the locale `tr`/`tr_args`/`current_locale` resolve to, bypassing
+/// environment and platform detection entirely. Accepts both `tr_TR` and `tr-TR`
+/// style separators, and strips an `.UTF-8`-style encoding suffix if present.
+/// ```rust,ignore
+/// use simple_localization::set_locale;
+///
+/// set_locale("tr_TR"); // or "tr-TR", or "tr_TR.UTF-8"
+/// ```
+pub fn set_locale(lang: &str) {
+    *LOCALE_OVERRIDE.lock().unwrap() = Some(normalize_locale(lang));
+}
+
+/// Clears a locale previously set via `set_locale`, reverting `current_locale`
+/// (and therefore `tr`/`tr_args`) to environment/platform detection.
+/// ```rust,ignore
+/// use simple_localization::clear_locale;
+///
+/// clear_locale();
+/// ```
+pub fn clear_locale() {
+    *LOCALE_OVERRIDE.lock().unwrap() = None;
+}
+
+/// Returns the locale that `tr`/`tr_args` currently resolve to: an explicitly-set
+/// locale (see `set_locale`), else `LANG`/`LC_ALL`, else platform detection, else
+/// `"en_US"`.
+pub fn current_locale() -> String {
+    detect_locale()
+}
+
+/// Strips an `.UTF-8`-style encoding suffix and normalizes the `tr-TR` / `tr_TR`
+/// separator styles to `tr_TR`.
+fn normalize_locale(raw: &str) -> String {
+    raw.split('.').next().unwrap_or(raw).replace('-', "_")
+}
+
+fn env_locale() -> Option<String> {
+    env::var("LANG").or_else(|_| env::var("LC_ALL")).ok()
+}
+
+#[cfg(unix)]
+fn platform_locale() -> Option<String> {
+    use std::ffi::{CStr, CString};
+
+    unsafe {
+        // Without this, `setlocale(LC_COLLATE, null)` just reports the "C" locale
+        // the process started in, never the one the environment actually asks for.
+        let empty = CString::new("").unwrap();
+        libc::setlocale(libc::LC_ALL, empty.as_ptr());
+
+        let locale_ptr = libc::setlocale(libc::LC_COLLATE, std::ptr::null());
+        if locale_ptr.is_null() {
+            return None;
         }
+
+        let locale = CStr::from_ptr(locale_ptr).to_str().ok()?.to_string();
+        // "C"/"POSIX" mean no locale was actually configured, so fall through to
+        // `LANG`/`LC_ALL` having been unset rather than treating it as a real locale.
+        if locale == "C" || locale == "POSIX" {
+            return None;
+        }
+
+        Some(locale)
     }
 }
 
-/// Get translation of the `text` in system's language(`env::var("LANG")`)
-/// If translation exists returns the translation   
+#[cfg(target_arch = "wasm32")]
+fn platform_locale() -> Option<String> {
+    web_sys::window()?.navigator().language()
+}
+
+#[cfg(not(any(unix, target_arch = "wasm32")))]
+fn platform_locale() -> Option<String> {
+    None
+}
+
+fn detect_locale() -> String {
+    if let Some(locale) = LOCALE_OVERRIDE.lock().unwrap().clone() {
+        return locale;
+    }
+
+    if let Some(locale) = env_locale() {
+        return normalize_locale(&locale);
+    }
+
+    if let Some(locale) = platform_locale() {
+        return normalize_locale(&locale);
+    }
+
+    "en_US".to_string()
+}
+
+/// Get translation of the `text` in the detected system language. Resolution order:
+/// an explicitly-set locale (`set_locale`), `LANG`/`LC_ALL`, platform detection
+/// (`setlocale(LC_COLLATE, ...)` on Unix, `navigator.language()` on `wasm32`), then
+/// `"en_US"`.
+/// If translation exists returns the translation
 /// else returns the `text` back.
 /// ```rust,ignore
 /// use simple_localization::tr;
-/// 
+///
 /// // localization/tr_TR file exists and `LANG=tr_TR.UTF-8`
 /// let text:&str = tr("Hello"); // "Merhaba"
-/// 
+///
 /// // localization/tr_TR file doesn't exists and `LANG=tr_TR.UTF-8`
 /// let text:&str = tr("Hello"); // "Hello"
-/// 
+///
 /// // localization/tr_TR file exists and `LANG=ar_QA.UTF-8`
 /// let text:&str = tr("Hello"); // "Hello"
 /// ```
-/// 
 pub fn tr(text: &str) -> &str {
-    let lang = match env::var("LANG") {
-        Ok(l) => l, // this returns "en_US.UTF-8"
-        Err(_) => {
-            eprintln!("Translation Error: 'LANG' environment variable doesn't exist");
-            return text;
+    trl(text, &current_locale())
+}
+
+/// Get translation of the `text` in a spesific language, replacing `$placeholder`
+/// tokens with the values given in `args`.
+/// If translation exists returns the translation with its placeholders filled in,
+/// else returns the `text` back with its placeholders filled in.
+/// ```rust,ignore
+/// use simple_localization::trl_args;
+/// use std::collections::HashMap;
+///
+/// // localization/en_US file contains "Welcome back, $name! You have $count messages" => "Welcome back, $name! You have $count messages"
+/// let mut args = HashMap::new();
+/// args.insert("name", "John".to_string());
+/// args.insert("count", "5".to_string());
+/// let text = trl_args("Welcome back, $name! You have $count messages", "en_US", &args);
+/// ```
+///
+pub fn trl_args(text: &str, lang: &str, args: &HashMap<&str, String>) -> String {
+    interpolate(trl(text, lang), args, text, lang)
+}
+
+/// Replaces `$placeholder` tokens in `template` with the values from `args`, warning
+/// (and leaving the token untouched) for any that aren't supplied. `context` is the
+/// original key/text, used only for the warning message.
+fn interpolate(template: &str, args: &HashMap<&str, String>, context: &str, lang: &str) -> String {
+    PLACEHOLDER_REGEX
+        .replace_all(template, |caps: &Captures| {
+            let token = &caps[1];
+            match args.get(token) {
+                Some(value) => value.clone(),
+                None => {
+                    eprintln!("Translation Error: No argument named '{token}' was supplied for '{context}' in '{lang}' language");
+                    caps[0].to_string()
+                }
+            }
+        })
+        .into_owned()
+}
+
+/// Get the plural-appropriate translation of `key` in `lang` for `count`, interpolating
+/// `$count` into the selected variant. `key` must have a `plural "key" { ... }` block in
+/// the translation file (see the crate docs). Falls back to the `other` variant when the
+/// computed category has no variant of its own, then to the language set via
+/// `set_fallback_language` (same as `trl`), and finally to `key` itself when no plural
+/// block exists for it anywhere in the chain.
+/// ```rust,ignore
+/// use simple_localization::trl_plural;
+///
+/// // localization/en_US has: plural "file_count" { one => "$count file" other => "$count files" }
+/// let text = trl_plural("file_count", "en_US", 1); // "1 file"
+/// let text = trl_plural("file_count", "en_US", 5); // "5 files"
+/// ```
+pub fn trl_plural(key: &str, lang: &str, count: u64) -> String {
+    let category = plural_category(lang, count);
+
+    let template = resolve_plural_variant(lang, key, category).or_else(|| {
+        let fallback_lang = FALLBACK_LANGUAGE.lock().unwrap().clone()?;
+        if fallback_lang == lang {
+            return None;
         }
-    };
 
-    let lang_vec:Vec<&str> = lang.split(".").collect();
-    let lang_str:&str = match lang_vec.first() {
-        Some(&l) => l, // this returns "en_US"
+        resolve_plural_variant(&fallback_lang, key, category)
+    });
+
+    let template = match template {
+        Some(value) => value,
         None => {
-            eprintln!("Translation Error: 'LANG' environment variable is not suitable to parse: {lang} (example: en_US.UTF-8)");
-            return text;
+            report_missing(lang, key);
+            key
         }
     };
 
-    trl(text, lang_str)
+    let mut args: HashMap<&str, String> = HashMap::new();
+    args.insert("count", count.to_string());
+
+    interpolate(template, &args, key, lang)
+}
+
+/// Get translation of the `text` in the locale `current_locale` resolves to, replacing
+/// `$placeholder` tokens with the values given in `args`.
+/// If translation exists returns the translation with its placeholders filled in,
+/// else returns the `text` back with its placeholders filled in.
+/// ```rust,ignore
+/// use simple_localization::tr_args;
+/// use std::collections::HashMap;
+///
+/// let mut args = HashMap::new();
+/// args.insert("name", "John".to_string());
+/// let text = tr_args("Hello, $name!", &args);
+/// ```
+///
+pub fn tr_args(text: &str, args: &HashMap<&str, String>) -> String {
+    trl_args(text, &current_locale(), args)
+}
+
+// Generated by build.rs: `VALID_KEYS` lists every translation key found under
+// `LOCALIZATION_DIR`, across both the custom format and JSON files (`plural`
+// blocks are stripped before scanning, since `trl_plural` checks those separately).
+include!(concat!(env!("OUT_DIR"), "/valid_keys.rs"));
+
+#[doc(hidden)]
+pub const fn __str_eq(a: &str, b: &str) -> bool {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+#[doc(hidden)]
+pub const fn __key_is_valid(text: &str) -> bool {
+    let mut i = 0;
+    while i < VALID_KEYS.len() {
+        if __str_eq(VALID_KEYS[i], text) {
+            return true;
+        }
+        i += 1;
+    }
+
+    false
+}
+
+/// Compile-time checked variant of `tr`. `build.rs` scans every file under
+/// `LOCALIZATION_DIR` to collect the set of valid source keys; passing a literal
+/// that isn't among them fails to compile instead of warning at runtime. Keys are
+/// compared by their actual string value, not by literal token spelling, so a
+/// multi-line or raw-string key checks out the same regardless of how it's written
+/// at the call site. Expands to a plain `tr()` call, so its runtime behavior is
+/// identical.
+/// ```rust,ignore
+/// use simple_localization::tr;
+///
+/// let text = simple_localization::tr!("Hello"); // fails to compile if "Hello" isn't a known key
+/// ```
+#[macro_export]
+macro_rules! tr {
+    ($text:literal) => {{
+        const _: () = assert!(
+            $crate::__key_is_valid($text),
+            concat!("simple_localization: unknown translation key: ", $text)
+        );
+        $crate::tr($text)
+    }};
 }
 
 
+/// Serializes tests that mutate the process-global `FALLBACK_LANGUAGE`/
+/// `LOCALE_OVERRIDE` state: `cargo test` runs tests in parallel by default, and two
+/// of these racing can observe each other's in-progress state instead of their own.
+#[cfg(test)]
+static TEST_STATE_LOCK: Mutex<()> = Mutex::new(());
+
 #[cfg(test)]
 mod tr_tests {
     use super::*;
@@ -193,7 +700,225 @@ Buraya istediğin her şeyi yazabilirsin.
 
 Bu yazının çevirisi bir sonraki tırnak içindeki yazıdır."#
         );
-    }    
+    }
+}
+
+#[cfg(test)]
+mod tr_macro_tests {
+    use super::*;
+
+    #[test]
+    fn key_equality_is_by_value_not_token_spelling() {
+        // tr!'s whole point is comparing by *value*, not by how the literal was
+        // written - an escaped-literal spelling and a raw string with a real
+        // newline must compare equal.
+        assert!(__str_eq("line1\nline2", "line1\nline2"));
+        assert!(__str_eq(
+            "line1\nline2",
+            r#"line1
+line2"#
+        ));
+        assert!(!__str_eq("Hello", "Hello!"));
+    }
+
+    #[test]
+    fn multiline_key_is_recognized_via_tr_macro() {
+        let text = tr!(r#"This is a multiline text.
+
+You can write anything you want here.
+
+Don't need to use \n.
+
+The translation of this is next the quoted text."#);
+
+        assert_eq!(
+            text,
+            r#"Bu bir çok satırlı yazı.
+
+Buraya istediğin her şeyi yazabilirsin.
+
+\n kullanman gerekmez.
+
+Bu yazının çevirisi bir sonraki tırnak içindeki yazıdır."#
+        );
+    }
+}
+
+#[cfg(test)]
+mod plural_tests {
+    use super::*;
+
+    #[test]
+    fn parses_plural_block() {
+        let translations = create_plural_hashmap(
+            r#"plural "file_count" {
+                one => "$count file"
+                other => "$count files"
+            }"#,
+        );
+
+        let variants = translations.get("file_count").unwrap();
+        assert_eq!(variants.get(&PluralCategory::One), Some(&"$count file"));
+        assert_eq!(variants.get(&PluralCategory::Other), Some(&"$count files"));
+    }
+
+    #[test]
+    fn ordinary_key_after_a_plural_block_is_not_swallowed() {
+        // Without stripping the plural block first, ONE_LINE_REGEX's non-greedy
+        // match spans from "file_count" all the way to the next closing quote,
+        // swallowing "Hello" into one bogus key.
+        let stripped = strip_plural_blocks(
+            r#"plural "file_count" {
+                one => "$count file"
+                other => "$count files"
+            }
+
+            "Hello" => "Merhaba""#,
+        );
+
+        let translations = create_translation_hashmap(stripped);
+        assert_eq!(translations.get("Hello"), Some(&"Merhaba"));
+    }
+
+    #[test]
+    fn english_plural_category() {
+        assert_eq!(plural_category("en_US", 1), PluralCategory::One);
+        assert_eq!(plural_category("en_US", 5), PluralCategory::Other);
+    }
+
+    #[test]
+    fn arabic_plural_category() {
+        assert_eq!(plural_category("ar_QA", 0), PluralCategory::Zero);
+        assert_eq!(plural_category("ar_QA", 2), PluralCategory::Two);
+        assert_eq!(plural_category("ar_QA", 5), PluralCategory::Few);
+        assert_eq!(plural_category("ar_QA", 15), PluralCategory::Many);
+    }
+
+    #[test]
+    fn turkish_plural_category_is_always_other() {
+        assert_eq!(plural_category("tr_TR", 1), PluralCategory::Other);
+        assert_eq!(plural_category("tr_TR", 5), PluralCategory::Other);
+    }
+
+    #[test]
+    fn trl_plural_selects_and_interpolates() {
+        // localization/en_US has a "file_count" plural block
+        assert_eq!(trl_plural("file_count", "en_US", 1), "1 file");
+        assert_eq!(trl_plural("file_count", "en_US", 5), "5 files");
+    }
+
+    #[test]
+    fn trl_plural_uses_fallback_language() {
+        let _guard = TEST_STATE_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        set_fallback_language("en_US");
+
+        // localization/ar_QA doesn't have a "file_count" plural block
+        assert_eq!(trl_plural("file_count", "ar_QA", 5), "5 files");
+
+        clear_fallback_language();
+    }
+}
+
+#[cfg(test)]
+mod json_translation_tests {
+    use super::*;
+
+    #[test]
+    fn parses_json_object() {
+        let translations = create_translation_hashmap_json(r#"{ "Hello": "Merhaba" }"#);
+        assert_eq!(translations.get("Hello"), Some(&"Merhaba"));
+    }
+
+    #[test]
+    fn parses_values_with_escape_sequences() {
+        let translations = create_translation_hashmap_json(r#"{"Multi": "line1\nline2", "Hello": "Merhaba"}"#);
+        assert_eq!(translations.get("Multi"), Some(&"line1\nline2"));
+        assert_eq!(translations.get("Hello"), Some(&"Merhaba"));
+    }
+}
+
+#[cfg(test)]
+mod locale_tests {
+    use super::*;
+
+    #[test]
+    fn set_locale_overrides_detection() {
+        let _guard = TEST_STATE_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        set_locale("tr-TR");
+        assert_eq!(current_locale(), "tr_TR");
+        assert_eq!(tr("Hello"), "Merhaba");
+
+        clear_locale();
+    }
+
+    #[test]
+    fn normalizes_encoding_suffix() {
+        assert_eq!(normalize_locale("en_US.UTF-8"), "en_US");
+        assert_eq!(normalize_locale("tr-TR"), "tr_TR");
+    }
+}
+
+#[cfg(test)]
+mod fallback_tests {
+    use super::*;
+
+    #[test]
+    fn falls_back_to_base_language() {
+        // localization/tr file exists, localization/tr_TR doesn't
+        assert_eq!(trl("Hello", "tr_TR"), "Merhaba");
+    }
+
+    #[test]
+    fn falls_back_to_configured_language() {
+        let _guard = TEST_STATE_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        set_fallback_language("en_US");
+
+        // localization/ar_QA doesn't have "Hello"
+        assert_eq!(trl("Hello", "ar_QA"), "Hello");
+
+        clear_fallback_language();
+    }
+}
+
+#[cfg(test)]
+mod missing_translations_tests {
+    use super::*;
+
+    #[test]
+    fn reports_each_missing_pair_once() {
+        trl("Nonexistent key", "tr_TR");
+        trl("Nonexistent key", "tr_TR");
+
+        let report = missing_translations();
+        assert_eq!(report.get("tr_TR").unwrap().iter().filter(|k| *k == "Nonexistent key").count(), 1);
+    }
+}
+
+#[cfg(test)]
+mod trl_args_tests {
+    use super::*;
+
+    #[test]
+    fn replaces_known_placeholders() {
+        let mut args: HashMap<&str, String> = HashMap::new();
+        args.insert("name", "John".to_string());
+        args.insert("count", "5".to_string());
+
+        assert_eq!(
+            trl_args("Welcome back, $name! You have $count messages", "en_US", &args),
+            "Welcome back, John! You have 5 messages"
+        );
+    }
+
+    #[test]
+    fn leaves_unknown_placeholders_untouched() {
+        let args: HashMap<&str, String> = HashMap::new();
+
+        assert_eq!(trl_args("Hello $name", "en_US", &args), "Hello $name");
+    }
 }
 
 #[cfg(test)]